@@ -1,5 +1,87 @@
+use std::error::Error;
 use std::fmt::{format, Debug, Arguments};
 
+/// Builds the decorated banner text shared by every panic message this crate produces, so that the
+/// `log` feature can emit the exact same text via `log::error!` before panicking with it rather
+/// than formatting it twice. Not part of the public API: it's `pub` but `#[doc(hidden)]`, and only
+/// this crate's own functions call it.
+///
+///  * `header`: The line describing what went wrong, eg. `"unwrap! called on Result::Err"`.
+///  * `context`: The rendered `unwrap_ctx!` breadcrumb stack, if one was supplied.
+///  * `value`: The formatted value being unwrapped, if there is one to show.
+///  * `backtrace`: The resolved call-stack backtrace, if the `backtrace` feature captured one.
+#[doc(hidden)]
+#[allow(clippy::too_many_arguments)]
+pub fn format_banner(header: &str, context: Option<&str>, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32, value: Option<&str>, backtrace: Option<&str>) -> String {
+    let bar = "!".repeat(80);
+    let mut banner = String::new();
+    banner.push_str("\n\n");
+    banner.push_str(&bar);
+    banner.push('\n');
+    banner.push_str(&format!("!   {:<75}!\n", header));
+    banner.push_str(&bar);
+    banner.push('\n');
+    banner.push_str(&format!("{}:{},{} in {}\n", file, line_number, column, module_path));
+    if let Some(context) = context {
+        banner.push_str(context);
+        banner.push('\n');
+    }
+    if let Some(args) = message {
+        banner.push_str(&format(args));
+        banner.push('\n');
+    }
+    banner.push('\n');
+    if let Some(value) = value {
+        banner.push_str(value);
+        banner.push_str("\n\n");
+    }
+    if let Some(backtrace) = backtrace {
+        banner.push_str(backtrace);
+        banner.push_str("\n\n");
+    }
+    banner
+}
+
+/// Joins an ordered list of `unwrap_ctx!` context strings into a "while doing X" breadcrumb stack,
+/// indenting each level so the most recent context is the most indented. Returns `None` for an
+/// empty list so callers can fall back to the plain message form.
+fn format_context_stack(contexts: &[&str]) -> Option<String> {
+    if contexts.is_empty() {
+        return None;
+    }
+
+    let mut stack = String::new();
+    for (depth, context) in contexts.iter().enumerate() {
+        if depth > 0 {
+            stack.push('\n');
+        }
+        stack.push_str(&"    ".repeat(depth));
+        stack.push_str("while doing: ");
+        stack.push_str(context);
+    }
+    Some(stack)
+}
+
+/// Captures the current call-stack backtrace for the `backtrace` feature, honouring
+/// `RUST_BACKTRACE` the same way `std::backtrace::Backtrace` always does. Returns `None` when the
+/// feature is disabled or the environment doesn't request backtrace capture, so there's no cost
+/// in the common case.
+#[cfg(feature = "backtrace")]
+fn capture_backtrace() -> Option<String> {
+    use std::backtrace::{Backtrace, BacktraceStatus};
+
+    let backtrace = Backtrace::capture();
+    match backtrace.status() {
+        BacktraceStatus::Captured => Some(format!("backtrace:\n{}", backtrace)),
+        _ => None,
+    }
+}
+
+#[cfg(not(feature = "backtrace"))]
+fn capture_backtrace() -> Option<String> {
+    None
+}
+
 /// Types which can be unwrapped and which may want to print a verbose error message when they are
 /// unwrapped incorrectly. This trait is implemented for `Result` and `Option` as a replacement for
 /// their inherent `unwrap` methods. This trait is intended to be used via this crate's `unwrap!`
@@ -26,94 +108,131 @@ pub trait VerboseUnwrap {
     ///  * `line_number`: The line number where this method is being called from.
     ///  * `column`: The column number where this method is being called from
     fn verbose_unwrap(self, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32) -> Self::Wrapped;
+
+    /// Like `verbose_unwrap`, but also emits the same diagnostic through `log::error!` immediately
+    /// before panicking, so that it reaches the configured log sink even when the panic output
+    /// itself is swallowed or interleaved. Requires the `log` feature. This method is intended to
+    /// be called via this crate's `unwrap_log!` macro.
+    #[cfg(feature = "log")]
+    fn verbose_unwrap_log(self, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32) -> Self::Wrapped;
+
+    /// Unwrap the value like `verbose_unwrap`, but prepend an ordered stack of human-readable
+    /// context strings describing what the program was attempting, rendered as nested "while
+    /// doing" lines between the location banner and the value dump. This method is intended to be
+    /// called via this crate's `unwrap_ctx!` macro.
+    fn verbose_unwrap_ctx(self, contexts: &[&str], module_path: &str, file: &str, line_number: u32, column: u32) -> Self::Wrapped;
 }
 
 impl<T, E: Debug> VerboseUnwrap for Result<T, E> {
     type Wrapped = T;
 
     fn verbose_unwrap(self, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32) -> T {
+        result_verbose_unwrap(self, message, module_path, file, line_number, column, false)
+    }
+
+    #[cfg(feature = "log")]
+    fn verbose_unwrap_log(self, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32) -> T {
+        result_verbose_unwrap(self, message, module_path, file, line_number, column, true)
+    }
+
+    fn verbose_unwrap_ctx(self, contexts: &[&str], module_path: &str, file: &str, line_number: u32, column: u32) -> T {
         match self {
             Ok(t) => t,
             Err(e) => {
-                // TODO(canndrew): As soon as impl specialisation lands specialise this to display
-                // the error and it's chain of causes.
-                /*
-                let mut error_str = String::new();
-                let mut error: &Error = &e;
-                loop {
-                    error_str.push_str(format!("{}\n", error));
-                    error = match error.cause() {
-                        Some(e) => e,
-                        None => break,
-                    }
-                }
-                */
-
-                match message {
-                    Some(args) => {
-                        let msg = format(args);
-                        panic!("\n\
-\n\
-!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!\n\
-!   unwrap! called on Result::Err                                              !\n\
-!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!\n\
-{}:{},{} in {}\n\
-{}\n\
-\n\
-{:?}\n\
-\n", file, line_number, column, module_path, msg, Err::<(), E>(e));
-                    },
-                    None => {
-                        panic!("\n\
-\n\
-!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!\n\
-!   unwrap! called on Result::Err                                              !\n\
-!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!\n\
-{}:{},{} in {}\n\
-\n\
-{:?}\n\
-\n", file, line_number, column, module_path, Err::<(), E>(e));
-                    },
-                }
+                let value = format!("{:?}", Err::<(), E>(e));
+                let backtrace = capture_backtrace();
+                let context = format_context_stack(contexts);
+                panic!("{}", format_banner("unwrap_ctx! called on Result::Err", context.as_deref(), None, module_path, file, line_number, column, Some(&value), backtrace.as_deref()));
             },
         }
     }
 }
 
+fn result_verbose_unwrap<T, E: Debug>(result: Result<T, E>, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32, also_log: bool) -> T {
+    match result {
+        Ok(t) => t,
+        Err(e) => {
+            // TODO(canndrew): As soon as impl specialisation lands specialise this to display
+            // the error and it's chain of causes.
+            /*
+            let mut error_str = String::new();
+            let mut error: &Error = &e;
+            loop {
+                error_str.push_str(format!("{}\n", error));
+                error = match error.cause() {
+                    Some(e) => e,
+                    None => break,
+                }
+            }
+            */
+
+            let value = format!("{:?}", Err::<(), E>(e));
+            let backtrace = capture_backtrace();
+            let banner = format_banner("unwrap! called on Result::Err", None, message, module_path, file, line_number, column, Some(&value), backtrace.as_deref());
+            #[cfg(feature = "log")]
+            if also_log {
+                log::error!("{}", banner);
+            }
+            #[cfg(not(feature = "log"))]
+            let _ = also_log;
+            panic!("{}", banner);
+        },
+    }
+}
+
 impl<T> VerboseUnwrap for Option<T> {
     type Wrapped = T;
 
     fn verbose_unwrap(self, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32) -> T {
+        option_verbose_unwrap(self, message, module_path, file, line_number, column, false)
+    }
+
+    #[cfg(feature = "log")]
+    fn verbose_unwrap_log(self, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32) -> T {
+        option_verbose_unwrap(self, message, module_path, file, line_number, column, true)
+    }
+
+    fn verbose_unwrap_ctx(self, contexts: &[&str], module_path: &str, file: &str, line_number: u32, column: u32) -> T {
         match self {
             Some(t) => t,
             None => {
-                match message {
-                    Some(args) => {
-                        let msg = format(args);
-                        panic!("\n\
-\n\
-!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!\n\
-!   unwrap! called on Option::None                                             !\n\
-!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!\n\
-{}:{},{} in {}\n\
-{}\n\
-\n", file, line_number, column, module_path, msg);
-                    },
-                    None => {
-                        panic!("\n\
-\n\
-!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!\n\
-!   unwrap! called on Option::None                                             !\n\
-!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!\n\
-{}:{},{} in {}\n\
-\n", file, line_number, column, module_path);
-                    },
-                }
+                let backtrace = capture_backtrace();
+                let context = format_context_stack(contexts);
+                panic!("{}", format_banner("unwrap_ctx! called on Option::None", context.as_deref(), None, module_path, file, line_number, column, None, backtrace.as_deref()));
             },
         }
     }
 }
 
+fn option_verbose_unwrap<T>(option: Option<T>, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32, also_log: bool) -> T {
+    match option {
+        Some(t) => t,
+        None => {
+            let backtrace = capture_backtrace();
+            let banner = format_banner("unwrap! called on Option::None", None, message, module_path, file, line_number, column, None, backtrace.as_deref());
+            #[cfg(feature = "log")]
+            if also_log {
+                log::error!("{}", banner);
+            }
+            #[cfg(not(feature = "log"))]
+            let _ = also_log;
+            panic!("{}", banner);
+        },
+    }
+}
+
+/// Panics with a decorated message reporting that a pattern given to `unwrap!` failed to match.
+/// This function is not intended to be called directly; it's called by the pattern-matching arms
+/// of the `unwrap!` macro. It's public only so that it's visible from the macro expansion.
+///
+/// The value that failed to match isn't printed since, unlike `Result`/`Option`, there's no bound
+/// on the scrutinee requiring it to implement `Debug`.
+#[doc(hidden)]
+pub fn unwrap_pattern_match_failed(pattern: &str, module_path: &str, file: &str, line_number: u32, column: u32) -> ! {
+    let value = format!("expected pattern: {}", pattern);
+    panic!("{}", format_banner("unwrap! pattern did not match", None, None, module_path, file, line_number, column, Some(&value), None));
+}
+
 /// A replacement for calling `unwrap()` on a `Result` or `Option`.
 ///
 /// This macro is intended to be used in all cases where one would `unwrap` a `Result` or `Option`
@@ -132,8 +251,42 @@ impl<T> VerboseUnwrap for Option<T> {
 /// assert_eq!(string_length, 5);
 /// # }
 /// ```
+///
+/// This macro also has a pattern-matching form which asserts that an expression matches a given
+/// enum variant and evaluates to the variant's bound fields, for use with enums that don't
+/// implement `Debug` or don't represent errors:
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate unwrap;
+/// # fn main() {
+/// enum Shape {
+///     Circle(f64),
+///     Rectangle { width: f64, height: f64 },
+/// }
+///
+/// let shape = Shape::Rectangle { width: 3.0, height: 4.0 };
+/// let (width, height) = unwrap!(Shape::Rectangle { width, height, .. } = shape);
+/// assert_eq!(width * height, 12.0);
+/// # }
+/// ```
+///
+/// Every field in the pattern must be bound to a plain identifier (no `_`, literals or nested
+/// destructuring) since the bindings are what the macro evaluates to.
 #[macro_export]
 macro_rules! unwrap(
+    ($($variant:ident)::+ ( $($inner:ident),* $(,)? ) = $e:expr) => (
+        match $e {
+            $($variant)::+($($inner),*) => ($($inner),*),
+            _ => $crate::unwrap_pattern_match_failed(stringify!($($variant)::+($($inner),*)), module_path!(), file!(), line!(), column!()),
+        }
+    );
+    ($($variant:ident)::+ { $($field:ident),* $(,)? .. } = $e:expr) => (
+        match $e {
+            $($variant)::+ { $($field),* , .. } => ($($field),*),
+            _ => $crate::unwrap_pattern_match_failed(stringify!($($variant)::+ { $($field),*, .. }), module_path!(), file!(), line!(), column!()),
+        }
+    );
     ($e:expr) => (
         $crate::VerboseUnwrap::verbose_unwrap($e, None, module_path!(), file!(), line!(), column!())
     );
@@ -142,6 +295,150 @@ macro_rules! unwrap(
     );
 );
 
+/// Like `unwrap!`, but also logs the same diagnostic through `log::error!` immediately before
+/// panicking. Requires the `log` feature.
+///
+/// In many deployments the panic output is swallowed or interleaved with other output, but the
+/// structured log line (with `module_path`, `file`, `line` and the `{:?}` of the value) still
+/// reaches the configured log sink.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate unwrap;
+/// # fn main() {
+/// let some_option = Some("Hello".to_string());
+/// let string_length = unwrap_log!(some_option, "This is an optional user-supplied text.").len();
+/// assert_eq!(string_length, 5);
+/// # }
+/// ```
+#[cfg(feature = "log")]
+#[macro_export]
+macro_rules! unwrap_log(
+    ($e:expr) => (
+        $crate::VerboseUnwrap::verbose_unwrap_log($e, None, module_path!(), file!(), line!(), column!())
+    );
+    ($e:expr, $($arg:tt)*) => (
+        $crate::VerboseUnwrap::verbose_unwrap_log($e, Some(format_args!($($arg)*)), module_path!(), file!(), line!(), column!())
+    );
+);
+
+/// Like `unwrap!`, but instead of a one-off message, attaches an ordered stack of context strings
+/// describing what the program was attempting, eg. `"loading config"` then
+/// `"parsing section [net]"`. Each context is rendered as its own "while doing" line, indented by
+/// nesting depth, between the location banner and the `{:?}` dump. This macro takes no message
+/// argument, so a message cannot be given together with the contexts.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate unwrap;
+/// # fn main() {
+/// let some_option = Some("Hello".to_string());
+/// let string_length = unwrap_ctx!(some_option, ctx: ["loading config", "parsing section [net]"]).len();
+/// assert_eq!(string_length, 5);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! unwrap_ctx(
+    ($e:expr, ctx: [$($ctx:expr),* $(,)?]) => (
+        $crate::VerboseUnwrap::verbose_unwrap_ctx($e, &[$($ctx),*], module_path!(), file!(), line!(), column!())
+    );
+);
+
+
+
+/// Types which can be unwrapped and whose error's `source()` chain should be printed in full when
+/// they are unwrapped incorrectly. This trait is implemented for `Result<T, E>` where `E: Error`
+/// as an alternative to `VerboseUnwrap`, which can only print `E`'s `Debug` representation.
+/// Errors which don't implement `std::error::Error` should keep using `VerboseUnwrap`/`unwrap!`.
+/// This trait is intended to be used via this crate's `unwrap_chain!` macro.
+pub trait VerboseUnwrapError {
+    /// The wrapped type.
+    type Wrapped;
+
+    /// Unwrap the value into its inner type or panic with an error message that walks the error's
+    /// `source()` chain when the value cannot be unwrapped. This method is intended to be called
+    /// via this crate's `unwrap_chain!` macro.
+    ///
+    /// # Panics
+    ///
+    /// When the value cannot be unwrapped. Eg. on an `Err` value.
+    ///
+    /// # Arguments
+    ///
+    /// These arguments are used to print a useful diagnostic when the method panics.
+    ///
+    ///  * `message`: An optional message, printed alongside the rest of the info.
+    ///  * `module_path`: The module path where this method is being called from. Eg.
+    ///    `my_crate::my_module::my_function`
+    ///  * `file`: The filename where this method is being called from.
+    ///  * `line_number`: The line number where this method is being called from.
+    ///  * `column`: The column number where this method is being called from
+    fn verbose_unwrap_chain(self, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32) -> Self::Wrapped;
+}
+
+impl<T, E: Error> VerboseUnwrapError for Result<T, E> {
+    type Wrapped = T;
+
+    fn verbose_unwrap_chain(self, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32) -> T {
+        match self {
+            Ok(t) => t,
+            Err(e) => {
+                let mut chain = format!("{}", e);
+                let mut depth = 1;
+                let mut cur: Option<&dyn Error> = e.source();
+                while let Some(source) = cur {
+                    chain.push_str(&format!("\n{}caused by: {}", "    ".repeat(depth), source));
+                    depth += 1;
+                    cur = source.source();
+                }
+
+                let backtrace = capture_backtrace();
+                panic!("{}", format_banner("unwrap_chain! called on Result::Err", None, message, module_path, file, line_number, column, Some(&chain), backtrace.as_deref()));
+            },
+        }
+    }
+}
+
+/// A replacement for calling `unwrap()` on a `Result` whose error type implements
+/// `std::error::Error`.
+///
+/// Like `unwrap!`, but instead of dumping the error's `Debug` representation, this prints the
+/// error's `Display` representation followed by its full `source()` chain, one "caused by" line
+/// per level. Useful for errors built from `thiserror`/`anyhow`-style chains, where the immediate
+/// error's `Debug` impl doesn't show the underlying cause.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate unwrap;
+/// # use std::fmt;
+/// # #[derive(Debug)]
+/// # struct MyError;
+/// # impl fmt::Display for MyError {
+/// #     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "my error") }
+/// # }
+/// # impl std::error::Error for MyError {}
+/// # fn main() {
+/// let result: Result<u32, MyError> = Ok(32);
+/// let x = unwrap_chain!(result);
+/// assert_eq!(x, 32);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! unwrap_chain(
+    ($e:expr) => (
+        $crate::VerboseUnwrapError::verbose_unwrap_chain($e, None, module_path!(), file!(), line!(), column!())
+    );
+    ($e:expr, $($arg:tt)*) => (
+        $crate::VerboseUnwrapError::verbose_unwrap_chain($e, Some(format_args!($($arg)*)), module_path!(), file!(), line!(), column!())
+    );
+);
+
 
 
 /// Types which can be unwrapped into an error type and which may want to print a verbose error
@@ -171,43 +468,43 @@ pub trait VerboseUnwrapErr {
     ///  * `line_number`: The line number where this method is being called from.
     ///  * `column`: The column number where this method is being called from
     fn verbose_unwrap_err(self, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32) -> Self::Wrapped;
+
+    /// Like `verbose_unwrap_err`, but also emits the same diagnostic through `log::error!`
+    /// immediately before panicking, so that it reaches the configured log sink even when the
+    /// panic output itself is swallowed or interleaved. Requires the `log` feature. This method is
+    /// intended to be called via this crate's `unwrap_err_log!` macro.
+    #[cfg(feature = "log")]
+    fn verbose_unwrap_err_log(self, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32) -> Self::Wrapped;
 }
 
 impl<T: Debug, E> VerboseUnwrapErr for Result<T, E> {
     type Wrapped = E;
 
     fn verbose_unwrap_err(self, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32) -> E {
-        match self {
-            Err(e) => e,
-            Ok(t) => {
-                match message {
-                    Some(args) => {
-                        let msg = format(args);
-                        panic!("\n\
-\n\
-!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!\n\
-!   unwrap_err! called on Result::Ok                                           !\n\
-!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!\n\
-{}:{},{} in {}\n\
-{}\n\
-\n\
-{:?}\n\
-\n", file, line_number, column, module_path, msg, Ok::<T, ()>(t));
-                    },
-                    None => {
-                        panic!("\n\
-\n\
-!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!\n\
-!   unwrap_err! called on Result::Ok                                           !\n\
-!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!\n\
-{}:{},{} in {}\n\
-\n\
-{:?}\n\
-\n", file, line_number, column, module_path, Ok::<T, ()>(t));
-                    },
-                }
-            },
-        }
+        result_verbose_unwrap_err(self, message, module_path, file, line_number, column, false)
+    }
+
+    #[cfg(feature = "log")]
+    fn verbose_unwrap_err_log(self, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32) -> E {
+        result_verbose_unwrap_err(self, message, module_path, file, line_number, column, true)
+    }
+}
+
+fn result_verbose_unwrap_err<T: Debug, E>(result: Result<T, E>, message: Option<Arguments>, module_path: &str, file: &str, line_number: u32, column: u32, also_log: bool) -> E {
+    match result {
+        Err(e) => e,
+        Ok(t) => {
+            let value = format!("{:?}", Ok::<T, ()>(t));
+            let backtrace = capture_backtrace();
+            let banner = format_banner("unwrap_err! called on Result::Ok", None, message, module_path, file, line_number, column, Some(&value), backtrace.as_deref());
+            #[cfg(feature = "log")]
+            if also_log {
+                log::error!("{}", banner);
+            }
+            #[cfg(not(feature = "log"))]
+            let _ = also_log;
+            panic!("{}", banner);
+        },
     }
 }
 
@@ -240,8 +537,52 @@ macro_rules! unwrap_err(
     );
 );
 
+/// Like `unwrap_err!`, but also logs the same diagnostic through `log::error!` immediately before
+/// panicking. Requires the `log` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate unwrap;
+/// # fn main() {
+/// let some_result = Err::<u64, String>("Failed".to_string());
+/// let string_length = unwrap_err_log!(some_result, "This is an optional user-supplied text.").len();
+/// assert_eq!(string_length, 6);
+/// # }
+/// ```
+#[cfg(feature = "log")]
+#[macro_export]
+macro_rules! unwrap_err_log(
+    ($e:expr) => (
+        $crate::VerboseUnwrapErr::verbose_unwrap_err_log($e, None, module_path!(), file!(), line!(), column!())
+    );
+    ($e:expr, $($arg:tt)*) => (
+        $crate::VerboseUnwrapErr::verbose_unwrap_err_log($e, Some(format_args!($($arg)*)), module_path!(), file!(), line!(), column!())
+    );
+);
+
 #[cfg(test)]
 mod tests {
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct ErrorWithSource {
+        source: Option<Box<ErrorWithSource>>,
+    }
+
+    impl fmt::Display for ErrorWithSource {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "something went wrong")
+        }
+    }
+
+    impl std::error::Error for ErrorWithSource {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            self.source.as_deref().map(|e| e as &dyn std::error::Error)
+        }
+    }
+
     #[test]
     fn unwrap_result_ok() {
         let result: Result<u32, u32> = Ok(32);
@@ -272,6 +613,30 @@ mod tests {
         let _ = unwrap!(result);
     }
 
+    #[test]
+    fn unwrap_chain_result_ok() {
+        let x = unwrap_chain!(Ok::<u32, ErrorWithSource>(32));
+        let y = unwrap_chain!(Ok::<u32, ErrorWithSource>(32), "Here's a message");
+        assert_eq!(x, 32);
+        assert_eq!(y, 32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unwrap_chain_result_err_no_source() {
+        let result: Result<u32, ErrorWithSource> = Err(ErrorWithSource { source: None });
+        let _ = unwrap_chain!(result);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unwrap_chain_result_err_with_source() {
+        let inner = ErrorWithSource { source: None };
+        let outer = ErrorWithSource { source: Some(Box::new(inner)) };
+        let result: Result<u32, ErrorWithSource> = Err(outer);
+        let _ = unwrap_chain!(result, "Here's a message {}", 23);
+    }
+
     #[test]
     fn unwrap_option_some() {
         let option: Option<u32> = Some(32);
@@ -295,6 +660,74 @@ mod tests {
         let _ = unwrap!(option);
     }
 
+    enum Shape {
+        Circle(f64),
+        Rectangle { width: f64, height: f64 },
+    }
+
+    #[test]
+    fn unwrap_pattern_tuple_variant_matches() {
+        let shape = Shape::Circle(2.0);
+        let radius = unwrap!(Shape::Circle(radius) = shape);
+        assert_eq!(radius, 2.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unwrap_pattern_tuple_variant_does_not_match() {
+        let shape = Shape::Rectangle { width: 3.0, height: 4.0 };
+        let _ = unwrap!(Shape::Circle(radius) = shape);
+    }
+
+    #[test]
+    fn unwrap_pattern_struct_variant_matches() {
+        let shape = Shape::Rectangle { width: 3.0, height: 4.0 };
+        let (width, height) = unwrap!(Shape::Rectangle { width, height, .. } = shape);
+        assert_eq!(width * height, 12.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unwrap_pattern_struct_variant_does_not_match() {
+        let shape = Shape::Circle(2.0);
+        let _ = unwrap!(Shape::Rectangle { width, height, .. } = shape);
+    }
+
+    #[test]
+    fn unwrap_ctx_result_ok() {
+        let result: Result<u32, u32> = Ok(32);
+        let x = unwrap_ctx!(result, ctx: ["loading config"]);
+        assert_eq!(x, 32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unwrap_ctx_result_err() {
+        let result: Result<u32, u32> = Err(32);
+        let _ = unwrap_ctx!(result, ctx: ["loading config", "parsing section [net]"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unwrap_ctx_result_err_no_contexts() {
+        let result: Result<u32, u32> = Err(32);
+        let _ = unwrap_ctx!(result, ctx: []);
+    }
+
+    #[test]
+    fn unwrap_ctx_option_some() {
+        let option: Option<u32> = Some(32);
+        let x = unwrap_ctx!(option, ctx: ["loading config"]);
+        assert_eq!(x, 32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unwrap_ctx_option_none() {
+        let option: Option<u32> = None;
+        let _ = unwrap_ctx!(option, ctx: ["loading config"]);
+    }
+
     #[test]
     fn unwrap_err_result_err() {
         let result: Result<u32, u32> = Err(32);
@@ -324,4 +757,36 @@ mod tests {
         let result: Result<u32, u32> = Ok(32);
         let _ = unwrap_err!(result);
     }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn unwrap_log_result_ok() {
+        let result: Result<u32, u32> = Ok(32);
+        let x = unwrap_log!(result);
+        assert_eq!(x, 32);
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    #[should_panic]
+    fn unwrap_log_result_err() {
+        let result: Result<u32, u32> = Err(32);
+        let _ = unwrap_log!(result, "Here's a message");
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn unwrap_err_log_result_err() {
+        let result: Result<u32, u32> = Err(32);
+        let x = unwrap_err_log!(result);
+        assert_eq!(x, 32);
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    #[should_panic]
+    fn unwrap_err_log_result_ok() {
+        let result: Result<u32, u32> = Ok(32);
+        let _ = unwrap_err_log!(result, "Here's a message");
+    }
 }